@@ -1,7 +1,9 @@
-// main.rs for buttons_reversible_edit_changelog_module
+// comprehensive_demo.rs for buttons_reversible_edit_changelog
+//
+// Interactive walkthrough of the undo/redo changelog system.
+// Run with: cargo run --example comprehensive_demo
 
-mod buttons_reversible_edit_changelog_module;
-use buttons_reversible_edit_changelog_module::{
+use buttons_reversible_edit_changelog::{
     EditType, button_add_byte_make_log_file, button_base_clear_all_redo_logs,
     button_hexeditinplace_byte_make_log_file,
     button_make_changelog_from_user_character_action_level, button_remove_byte_make_log_file,
@@ -0,0 +1,672 @@
+//! buttons_reversible_edit_changelog
+//! Buttons: Reversible Character and Byte Undo Redo Changelog System
+//!
+//! Library entry point for the file-based undo/redo changelog system.
+//!
+//! # Project Context
+//! The module was originally shipped as a single source file that editors
+//! (e.g. the Lines editor) consumed by copy-pasting it into their own tree.
+//! This crate root exposes the same module as a normal Rust library so that
+//! an editor can depend on it directly, while the module file itself stays
+//! self-contained and copy-paste compatible.
+//!
+//! # Public API Overview
+//!
+//! ## Core Data Types
+//! - [`LogEntry`]: One inverse-changelog entry (what to do to UNDO a user edit)
+//!   - [`LogEntry::with_extra_metadata`] / [`LogEntry::extra_metadata`]: Uninterpreted
+//!     `key:value` lines (up to [`MAX_LOG_EXTRA_METADATA`]) kept and written back, so
+//!     newer versions and embedders can add data without breaking older readers
+//! - [`EditType`]: The kind of byte operation (add / rmv / edt)
+//! - [`ButtonError`] / [`ButtonResult`]: Error type for all changelog operations
+//!
+//! ## Making Changelogs (after the user edits the file)
+//! - [`button_make_changelog_from_user_character_action_level`]: Router for character-level actions
+//! - [`button_remove_byte_make_log_file`]: User added a byte, log says "remove"
+//! - [`button_add_byte_make_log_file`]: User removed a byte, log says "add"
+//! - [`button_hexeditinplace_byte_make_log_file`]: User hex-edited a byte, log says "edit back"
+//! - [`button_make_hexedit_in_place_changelog`]: Same, but reads the original byte from the file
+//!   (call before writing the new byte)
+//! - [`button_remove_multibyte_make_log_files`] / [`button_add_multibyte_make_log_files`]:
+//!   Multi-byte UTF-8 character log sets
+//! - [`button_log_string_insert`] / [`button_log_string_removal`]: Pasted or cut text of any
+//!   length, one set per character chained into one undo group
+//! - [`set_text_encoding`] / [`read_text_encoding`] + [`TextEncoding`]: UTF-16LE/BE targets;
+//!   character-level logging, overwrite and the line edits then work in 2- or 4-byte code units
+//!
+//! ## Multi-Process Coordination
+//! - [`read_changelog_generation`] / [`verify_changelog_generation`]: Generation counter bumped
+//!   by redo clears and rebaselines; stale callers get [`ButtonError::StaleGeneration`]
+//! - [`button_undo_redo_next_at_generation`]: Generation-checked undo/redo; the `_with_options`
+//!   entry points check `UndoOptions::expected_generation` under the changelog lock
+//! - Undo/redo steps hold [`CHANGELOG_LOCK_FILE_NAME`] in the directory they pop from, so two
+//!   processes cannot apply the same entry; a live lock past `UndoOptions::lock_wait` gives
+//!   [`ButtonError::ChangelogLocked`]
+//!
+//! ## Applying AND Logging In One Call
+//! - [`perform_and_log_add_byte`], [`perform_and_log_remove_byte`], [`perform_and_log_hexedit_byte`]:
+//!   Apply the user's edit and write its inverse entry; the entry is rolled back if the edit fails
+//! - [`set_inplace_hexedit_mode`] + [`overwrite_single_byte_in_place`]: Opt-in per file; hex-edits
+//!   and their undo/redo overwrite one byte (verified, fsynced) instead of copying the file
+//! - [`perform_and_log_overwrite_character`]: Overwrite mode; replaces one character with
+//!   another of any UTF-8 length in one rebuild, logged as one undo group
+//! - [`perform_and_log_delete_line`] / [`perform_and_log_insert_line`]: Whole lines (`dd`, `O`);
+//!   line breaks are found for the caller, one undo group per line
+//! - [`delete_to_line_end`]: Kill-line from the caret, logged in the file's undo directory
+//! - [`normalize_line_endings`] + [`LineEnding`]: CRLF to LF (or back) in one streaming pass
+//!   and one rebuild, one undo group; journal storage packs its logs in one append
+//! - [`perform_and_log_multi_cursor_edits`] + [`CursorEdit`]: Edits at many cursors (positions
+//!   in the unedited file, any order) in one rebuild and one undo group; returns the shifted
+//!   cursor positions in [`MultiCursorEditSummary`]
+//! - [`perform_and_log_block_insert`] / [`perform_and_log_block_delete`]: Rectangular block
+//!   edits (same column on consecutive lines) as one multi-cursor edit
+//! - [`truncate_file_to`] / [`extend_file_with`]: Cut or append a run of any length as ONE
+//!   `add_range` / `rmv_range` entry; runs over [`RANGE_PAYLOAD_INLINE_MAX_BYTES`] are kept in a
+//!   side file under [`RANGE_PAYLOAD_DIR_NAME`] ([`RangePayload`])
+//! - [`move_byte_range`]: Move a run elsewhere in the file in one rebuild, logged as ONE `mov`
+//!   entry that moves it back
+//! - [`import_patch`] + [`PatchImportSummary`]: Apply a unified diff or binary patch in one
+//!   rebuild; every changed byte is logged, as one undo group
+//! - [`replace_file_contents`] + [`BufferReplaceSummary`]: Replace the whole file (formatter
+//!   output, reload) logging only what a line-then-byte diff finds changed, as one undo group;
+//!   diffs over [`MAX_BUFFER_DIFF_EDITS`] edits fall back to coarser diffs
+//!
+//! ## Undo / Redo
+//! - [`button_undo_redo_next_inverse_changelog_pop_lifo`]: Undo (or redo) the next LIFO entry
+//! - [`ChangelogSession`]: Same undo/redo for rapid sequences; paths and settings are resolved
+//!   once per session instead of on every step; `Send + Sync`, so threads can share one
+//!   session through an `Arc` (steps are serialized internally)
+//! - [`WorkspaceUndo`]: "Undo last change in project": an append-only ordering journal under
+//!   a root directory maps each edit to its file's log set; `undo_last` / `redo_last` step
+//!   across files and stay consistent with the per-file histories after a crash
+//! - [`button_undo_all`]: Revert to the earliest logged state (everything becomes redoable)
+//! - [`mark_savepoint`] / [`is_dirty_since_savepoint`] / [`undo_to_last_savepoint`]:
+//!   "Modified since save" tracking and revert-to-saved
+//! - [`bookmark`] / [`list_bookmarks`] / [`undo_to_bookmark`]: Named history positions
+//! - [`undo_to_timestamp`]: Undo every edit logged after a point in time
+//! - [`set_undo_tree_mode`] / [`list_undo_branches`] / [`switch_undo_branch`]:
+//!   Vim-style undo tree: new edits after undo fork a branch instead of
+//!   clearing redo
+//! - [`coalesce_typed_insertion`] + [`button_undo_redo_group`]: Merge typing bursts
+//!   into undo groups so one undo removes a typed word
+//!   ([`button_undo_redo_group_with_options`] takes [`UndoOptions`])
+//! - [`coalesce_repeated_hexedit`]: Merge a hex-edit value scrub at one offset into one `edt`
+//!   entry holding the oldest original value
+//! - [`record_edit_cursor`] + [`button_undo_redo_next_with_cursor`]: Store the caret
+//!   ([`CursorPosition`]) with an edit and get it back from its undo and redo
+//! - [`record_edit_selection`] + [`button_undo_redo_group_with_selection`]: Same for the
+//!   [`SelectionRange`] of a grouped "delete selection", so undo highlights it again
+//! - [`create_checkpoint`] / [`create_checkpoint_if_due`] + [`undo_steps_with_checkpoints`]:
+//!   Full-file snapshots so deep undos replay from the nearest checkpoint
+//!   ([`undo_steps_with_checkpoints_with_options`] takes [`UndoOptions`])
+//! - [`restore_to_checkpoint`]: Rebuild a lost or damaged file from a checkpoint plus
+//!   the edits logged after it
+//! - [`reconstruct_missing_target`]: Recreate a deleted file from the newest checkpoint,
+//!   the rebaseline root snapshot, or an insert-only history
+//! - [`redo_all`]: Redo the whole redo stack in one file rewrite; redone steps stay undoable
+//!   ([`redo_all_with_options`] can force it over an external change)
+//! - [`button_undo_redo_next_with_toggle_cache`] + [`UndoRedoToggleCache`]:
+//!   Same, but an undo followed by its redo is served from memory (fast A/B comparison)
+//! - [`button_base_clear_all_redo_logs`] / [`button_safe_clear_all_redo_logs`]:
+//!   Clear the redo stack after a normal edit
+//! - [`set_redo_depth_limit`] / [`read_redo_depth_limit`]: Cap the redo stack;
+//!   each undo prunes the oldest redo steps beyond the limit
+//! - [`get_undo_changelog_directory_path`] / [`get_redo_changelog_directory_path`]:
+//!   Standard sidecar directory locations for a target file (NFD and NFC spellings of an
+//!   accented name share one directory)
+//! - [`set_central_changelog_root`] / [`CHANGELOG_ROOT_ENV_VAR`]: Keep all changelog and error-log
+//!   directories under one root (one folder per source directory) instead of next to each file
+//! - [`set_sidecar_directory_naming`] + [`SidecarDirectoryNaming`]: Hidden `.changelog_*` names;
+//!   existing histories under either naming are still found
+//! - [`read_changelog_directory_identity`] + [`ChangelogDirectoryIdentity`]: Target path,
+//!   creation time and format version recorded in each changelog directory's manifest
+//!   (non-UTF-8 file names get hash-keyed directories and an exact `target_path_hex:` line)
+//! - [`migrate_changelog`] + [`ChangelogMigrationSummary`]: Upgrade an older directory in
+//!   place (version marker, time stamps, one encoding) so it keeps working with this version
+//! - [`merge_changelogs`] + [`ChangelogMergeSummary`]: One history from two machines' copies
+//!   of a synced directory (shared prefix once, then by time; overlapping edits reported)
+//! - [`find_merge_conflicts`] + [`merge_changelogs_resolving`]: Overlapping edits that differ
+//!   are a [`MergeConflictReport`]; choose a side per [`MergeConflict`] to merge anyway
+//! - [`normalize_long_path`]: On Windows, paths over [`LONG_PATH_THRESHOLD`] are used in
+//!   extended-length (`\\?\`) form, so deep targets and their changelogs keep working
+//! - [`button_rename_target`]: Rename a file together with its changelog, redo and error-log
+//!   directories (or move just the history after the editor renamed it)
+//! - [`duplicate_changelog_for_save_as`]: Give a "Save As" copy its own copy of the undo/redo
+//!   history (hard-linked where possible)
+//! - [`export_history_json`] / [`import_history_json`]: One self-contained JSON document of
+//!   a changelog directory, to inspect, archive, or move a history between machines
+//! - [`pack_changelog`] / [`unpack_changelog`]: A whole changelog directory, byte for byte,
+//!   as one archive file (e.g. to attach an undo history to a bug report)
+//! - [`delete_file_with_undo`] / [`rename_file_with_undo`] / [`record_file_created`] +
+//!   [`undo_file_operation`] / [`redo_file_operation`]: File-level undo per folder; deleted
+//!   files wait in an archive under [`FILE_OPERATION_LOG_DIR_NAME`] ([`FileOperation`])
+//!
+//! ## Conflicts With External Changes
+//! - [`button_check_next_undo_conflict`]: Read-only check whether the next entry still matches the file
+//! - [`button_undo_redo_next_with_conflict_resolution`]: Undo/redo with a host-chosen [`ConflictResolution`]
+//! - [`TargetFingerprint`]: Target length/checksum recorded in log entries; undo/redo of a
+//!   file changed since fails with [`ButtonError::TargetModifiedExternally`]
+//! - [`button_undo_redo_next_with_options`] + [`UndoOptions`]: `force` applies the step anyway
+//!   once the user confirmed
+//! - [`rebaseline`]: Archive the stale history to a branch and restart undo from the current file
+//! - [`ExternalChangeWatcher`]: Polls the file (mtime + size, checksum on change) and reports
+//!   [`ExternalChange`]s to a callback; own edits go through `acknowledge_after`
+//!
+//! ## History Maintenance
+//! - [`compact_changelog`]: Drop edits that cancel out (typed then deleted, repeated hex-edits)
+//!   and no-op entries ([`LogEntry::is_no_op`], e.g. a byte hex-edited to its own value)
+//! - [`set_prune_policy`] / [`enforce_prune_policy`] + [`PrunePolicy`]: Cap undo history by
+//!   steps, age, or log size; applied automatically whenever a new edit is logged
+//! - [`set_ring_buffer_capacity`] / [`enforce_ring_buffer_capacity`]: Fixed-capacity history
+//!   whose log numbers are periodically renumbered from 0
+//! - [`set_changelog_quota`] + [`ChangelogQuota`]: Disk quota for the undo directory; writes
+//!   past it fail with [`ButtonError::QuotaExceeded`] or prune the oldest steps
+//! - [`set_changelog_storage`] + [`ChangelogStorage`]: Journal storage (format v2) packs all
+//!   but the newest [`JOURNAL_HOT_LOG_SETS`] log sets into one append-only file, instead of
+//!   one tiny file per entry
+//! - [`set_log_encoding`] + [`LogEncoding`]: Binary encoding (format v3) writes new log
+//!   entries as fixed-width [`LOG_BINARY_RECORD_SIZE`]-byte records instead of text lines
+//! - [`set_log_parse_mode`] + [`LogParseMode`]: Log files are parsed leniently (CRLF, stray
+//!   whitespace, lowercase hex); strict mode accepts only the exact written form
+//! - [`quarantine_unparseable_logs`]: Move log sets that cannot be parsed at all aside
+//! - [`set_error_log_retention`] + [`ErrorLogRetention`] / [`gc_error_logs`]: Limit the
+//!   `undoredo_errorlogs_*` subdirectories by count, age and size (default:
+//!   [`DEFAULT_ERROR_LOG_RETENTION`]); applied whenever a new error is logged
+//! - [`set_redaction_mode`]: Privacy mode; logs keep positions and a checksum only, the bytes
+//!   they restore go to an owner-only [`REDACTION_VAULT_DIR_NAME`] ([`LogEntry::is_redacted`])
+//! - [`set_log_codec`] + [`LogCodec`]: Host-supplied at-rest encoding (e.g. encryption) of
+//!   every log and vault file written; encoded files start with [`LOG_CODEC_MAGIC`]
+//! - [`set_log_signer`] + [`LogSigner`]: Host-supplied signatures on log entries, stored as
+//!   a last [`LOG_META_SIGNATURE_KEY`] line and verified on every read
+//!
+//! ## History Inspection
+//! - [`blame_range`]: Which logged operation last touched each byte of a range, and when
+//! - [`ChangelogIterator`]: Lazily walk parsed entries newest-first (bounded memory)
+//! - [`peek_next_undo`] / [`peek_next_redo`] / [`peek_next_log_set`]: Next step, without applying it
+//! - [`describe_next_undo`] / [`LogEntry::describe`]: Status-bar text for the next undo
+//! - [`validate_next_undo`]: Dry run: would the next undo succeed? (structured report)
+//! - [`count_available_undos`] / [`count_available_redos`]: Undo group counts (presses of
+//!   [`button_undo_redo_group`]) for enabling buttons
+//! - [`export_version_at_step`]: Write the version from K undos ago to a separate file
+//! - [`diff_against_history`]: Byte-level differences between the current file and K undos ago
+//! - [`export_as_patch`]: Net effect of the last K changes as a unified diff (text) or a
+//!   binary patch ([`PatchFormat`]), for review or for applying elsewhere
+//! - [`estimate_undo_cost`]: Expected file rebuilds and bytes read/written for the next K undos
+//! - [`history_by_author`] + [`AuthoredLogSet`]: The sets one user logged in a shared file
+//!   (new entries are stamped via [`set_changelog_author`] / [`CHANGELOG_AUTHOR_ENV_VAR`])
+//! - [`set_audit_log_policy`] + [`AuditLogPolicy`]: JSON-lines record of every log written,
+//!   undo, redo and clear (with time, pid and author) at [`audit_log_path`], size-rotated
+//!
+//! ## Positioning
+//! - [`byte_offset_of_line_col`] / [`line_col_of_byte_offset`]: Zero-based line and byte
+//!   column to a byte offset for the logging APIs, and back; streams the file once
+//! - [`char_index_to_byte_offset`]: Character index (as editor buffers count) to byte offset;
+//!   `_at_char_index` variants of the string-logging and overwrite APIs take one directly
+//! - [`detect_byte_order_mark`] + [`ByteOrderMark`], [`byte_offset_past_bom`]: Positions that
+//!   exclude a UTF-8/UTF-16 BOM (character index 0 and line 0 start past it), so edits never
+//!   hit the marker
+//!
+//! ## Byte Operations (used by undo, also usable directly)
+//! - [`add_single_byte_to_file`], [`remove_single_byte_from_file`], [`replace_single_byte_in_file`]
+//! - `*_with_progress` variants + [`ProgressSink`]: Same operations, reporting bytes
+//!   processed / total bytes (e.g. for a progress bar on huge files)
+//! - `*_cancellable` variants: Abort on an `AtomicBool` flag; the draft is removed, the
+//!   original is left untouched, and the call returns [`ButtonError::Cancelled`]
+//! - [`set_free_space_requirement`] / [`read_free_space_requirement`]: Free space (percent of
+//!   the file size) checked before a rebuild; short space fails early with
+//!   [`ButtonError::InsufficientDiskSpace`]; measured by the host's [`FreeSpaceProbe`]
+//!   ([`set_free_space_probe`]), skipped while none is set
+//! - [`set_sync_policy`] / [`read_sync_policy`] + [`SyncPolicy`]: fsync drafts, renames and
+//!   new log files (optionally their directories) so history survives power loss
+//! - [`set_backup_verification`]: Re-hash each `.backup` against the original before the swap
+//!   (re-copied once); a corrupt copy gives [`ButtonError::BackupCorrupted`], file unchanged
+//! - [`recover_interrupted_operation`]: Undo/redo steps are journaled; on startup this
+//!   finishes or rolls back a step interrupted by a crash
+//! - [`scan_and_recover_orphans`]: Clean up `.draft` / `.backup` files left by a crash,
+//!   restoring a lost file from its backup
+//! - [`set_scratch_file_naming`] + [`ScratchFileNaming`]: Other `.backup` / `.draft` suffixes,
+//!   or a scratch subdirectory ([`SCRATCH_SUBDIRECTORY_ENV_VAR`]), so user files are never hit
+//! - [`replace_file_with_draft`]: The atomic-replacement rename; on Windows it retries a
+//!   briefly locked file with backoff, then falls back to a journaled remove-then-rename
+//! - Rebuilds hold a `{file}.lock` (PID + time; stale locks are taken over), so two processes
+//!   cannot interleave on one file; a live lock past [`TARGET_LOCK_WAIT`] gives
+//!   [`ButtonError::TargetLocked`]
+//! - Read-only files, file systems and directories are refused before a lock, backup or log
+//!   entry exists, with [`ButtonError::ReadOnlyTarget`]
+//!
+//! # Example
+//! ```no_run
+//! use buttons_reversible_edit_changelog::{
+//!     EditType, button_make_changelog_from_user_character_action_level,
+//!     button_undo_redo_next_inverse_changelog_pop_lifo, get_undo_changelog_directory_path,
+//! };
+//! use std::path::Path;
+//!
+//! # fn main() -> buttons_reversible_edit_changelog::ButtonResult<()> {
+//! let target_file = Path::new("/absolute/path/to/file.txt");
+//! let log_dir = get_undo_changelog_directory_path(target_file)?;
+//!
+//! // User typed 'a' at position 0 (the editor already wrote it to the file)
+//! button_make_changelog_from_user_character_action_level(
+//!     target_file,
+//!     None,
+//!     None,
+//!     0,
+//!     EditType::AddCharacter,
+//!     &log_dir,
+//! )?;
+//!
+//! // User pressed undo
+//! button_undo_redo_next_inverse_changelog_pop_lifo(target_file, &log_dir)?;
+//! # Ok(())
+//! # }
+//! ```
+
+// The module follows its own documented rules (NASA-style explicit bounded
+// index loops, explicit `io::Error::new` construction, explicit error-mapping
+// closures). These clippy style lints conflict with those rules, so they are
+// allowed for the crate rather than rewriting the audited code paths.
+#![allow(
+    clippy::io_other_error,
+    clippy::redundant_closure,
+    clippy::unnecessary_lazy_evaluations,
+    clippy::assertions_on_constants,
+    clippy::manual_range_contains,
+    clippy::collapsible_if,
+    clippy::needless_range_loop,
+    clippy::explicit_counter_loop,
+    clippy::empty_line_after_doc_comments,
+    clippy::doc_overindented_list_items,
+    clippy::wrong_self_convention,
+    clippy::should_implement_trait
+)]
+
+pub mod buttons_reversible_edit_changelog_module;
+
+pub use buttons_reversible_edit_changelog_module::{
+    // Core data types and constants
+    AUDIT_LOG_FILE_NAME,
+    AuditLogPolicy,
+    AuthoredLogSet,
+    BOOKMARKS_DIR_NAME,
+    BRANCHES_DIR_NAME,
+    BlameEntry,
+    BufferReplaceSummary,
+    ButtonError,
+    ButtonResult,
+    ByteDifference,
+    ByteDifferenceKind,
+    ByteOrderMark,
+    CHANGELOG_ARCHIVE_MAGIC,
+    CHANGELOG_AUTHOR_ENV_VAR,
+    CHANGELOG_BINARY_LOG_FORMAT_VERSION,
+    CHANGELOG_FORMAT_VERSION,
+    CHANGELOG_ITERATOR_LOOKAHEAD,
+    CHANGELOG_JOURNAL_FILE_NAME,
+    CHANGELOG_JOURNAL_FORMAT_VERSION,
+    CHANGELOG_LOCK_FILE_NAME,
+    CHANGELOG_LOCK_WAIT,
+    CHANGELOG_ROOT_ENV_VAR,
+    CHECKPOINT_CONTENT_FILE_NAME,
+    CHECKPOINT_MANIFEST_FILE_NAME,
+    CHECKPOINT_PREFIX,
+    CHECKPOINTS_DIR_NAME,
+    CONFLICT_SNAPSHOT_DIR_NAME,
+    ChangelogDirectoryIdentity,
+    ChangelogIterator,
+    ChangelogMergeSummary,
+    ChangelogMigrationSummary,
+    ChangelogQuota,
+    ChangelogSession,
+    ChangelogStorage,
+    Checkpoint,
+    CheckpointUndoSummary,
+    CompactionSummary,
+    ConflictResolution,
+    ConflictResolutionOutcome,
+    CursorEdit,
+    CursorPosition,
+    DEFAULT_ERROR_LOG_RETENTION,
+    DEFAULT_FREE_SPACE_PERCENT,
+    DEFAULT_HEXEDIT_COALESCE_GAP,
+    DEFAULT_TOGGLE_CACHE_MAX_BYTES,
+    DEFAULT_TYPING_COALESCE_GAP,
+    EditType,
+    ErrorLogRetention,
+    ExternalChange,
+    ExternalChangeSink,
+    ExternalChangeWatcher,
+    FILE_OPERATION_ARCHIVE_DIR_NAME,
+    FILE_OPERATION_LOG_DIR_NAME,
+    FORK_POINT_FILE_NAME,
+    FREE_SPACE_CACHE_MAX_AGE,
+    FREE_SPACE_CHECK_MIN_FILE_BYTES,
+    FileOperation,
+    FreeSpaceProbe,
+    HIDDEN_DIR_PREFIX,
+    HISTORY_JSON_FORMAT,
+    HISTORY_JSON_VERSION,
+    HistoryBookmark,
+    InterruptedOperationOutcome,
+    InterruptedOperationRecovery,
+    JOURNAL_HOT_LOG_SETS,
+    LOG_BINARY_MAGIC,
+    LOG_BINARY_RECORD_SIZE,
+    LOG_CODEC_MAGIC,
+    LOG_DIR_PREFIX,
+    LOG_LETTER_SEQUENCE,
+    LOG_META_AUTHOR_KEY,
+    LOG_META_CREATED_AT_KEY,
+    LOG_META_CURSOR_KEY,
+    LOG_META_EXPECTED_BYTE_KEY,
+    LOG_META_GROUP_CONTINUE,
+    LOG_META_GROUP_KEY,
+    LOG_META_MOVE_DESTINATION_KEY,
+    LOG_META_RANGE_DATA_KEY,
+    LOG_META_RANGE_LENGTH_KEY,
+    LOG_META_RANGE_PAYLOAD_KEY,
+    LOG_META_REDACTED_KEY,
+    LOG_META_SELECTION_KEY,
+    LOG_META_SIGNATURE_KEY,
+    LOG_META_TARGET_CHECKSUM_KEY,
+    LOG_META_TARGET_LENGTH_KEY,
+    LOG_SIGNATURE_REJECTION_REASON,
+    LOG_STRICT_REJECTION_REASON,
+    LONG_PATH_THRESHOLD,
+    LineEnding,
+    LogCodec,
+    LogEncoding,
+    LogEntry,
+    LogParseMode,
+    LogSigner,
+    MANIFEST_AUDIT_LOG_BYTES_KEY,
+    MANIFEST_AUDIT_LOG_KEEP_KEY,
+    MANIFEST_CREATED_KEY,
+    MANIFEST_ERROR_LOG_MAX_AGE_KEY,
+    MANIFEST_ERROR_LOG_MAX_COUNT_KEY,
+    MANIFEST_ERROR_LOG_MAX_TOTAL_BYTES_KEY,
+    MANIFEST_FILE_NAME,
+    MANIFEST_FORMAT_VERSION_KEY,
+    MANIFEST_FREE_SPACE_PERCENT_KEY,
+    MANIFEST_GENERATION_KEY,
+    MANIFEST_HISTORY_TRUNCATED_KEY,
+    MANIFEST_INPLACE_HEXEDIT_KEY,
+    MANIFEST_LOG_ENCODING_KEY,
+    MANIFEST_PRUNE_MAX_AGE_KEY,
+    MANIFEST_PRUNE_MAX_ENTRIES_KEY,
+    MANIFEST_PRUNE_MAX_TOTAL_BYTES_KEY,
+    MANIFEST_QUOTA_ACTION_KEY,
+    MANIFEST_QUOTA_BYTES_KEY,
+    MANIFEST_REDACTION_KEY,
+    MANIFEST_REDO_DEPTH_LIMIT_KEY,
+    MANIFEST_RING_BUFFER_CAPACITY_KEY,
+    MANIFEST_ROLE_KEY,
+    MANIFEST_STORAGE_KEY,
+    MANIFEST_SYNC_POLICY_KEY,
+    MANIFEST_TARGET_PATH_HEX_KEY,
+    MANIFEST_TARGET_PATH_KEY,
+    MANIFEST_TEXT_ENCODING_KEY,
+    MANIFEST_UNDO_TREE_KEY,
+    MANIFEST_VERIFY_BACKUP_KEY,
+    MAX_AUDIT_LOG_ROTATED_FILES,
+    MAX_BOOKMARK_NAME_LENGTH,
+    MAX_BUFFER_DIFF_EDITS,
+    MAX_HISTORY_JSON_BYTES,
+    MAX_LINE_EDIT_BYTES,
+    MAX_LOG_AUTHOR_BYTES,
+    MAX_LOG_EXTRA_METADATA,
+    MAX_MULTI_CURSOR_CHANGED_BYTES,
+    MAX_PATCH_FILE_BYTES,
+    MAX_PATCH_IMPORT_CHANGED_BYTES,
+    MergeConflict,
+    MergeConflictReport,
+    MergeOverlap,
+    MergeSide,
+    MultiCursorEditSummary,
+    OrphanAction,
+    OrphanRecovery,
+    PENDING_OPERATION_DIR_NAME,
+    PROGRESS_REPORT_INTERVAL_BYTES,
+    PatchFormat,
+    PatchImportSummary,
+    ProgressSink,
+    PrunePolicy,
+    QUOTA_LOG_FILE_RESERVATION,
+    QuotaAction,
+    RANGE_PAYLOAD_DIR_NAME,
+    RANGE_PAYLOAD_INLINE_MAX_BYTES,
+    REBASELINE_BRANCH_PREFIX,
+    REDACTION_VAULT_DIR_NAME,
+    REDO_LOG_DIR_PREFIX,
+    REPLACEMENT_JOURNAL_SUFFIX,
+    ROOT_SNAPSHOT_FILE_NAME,
+    RangePayload,
+    RebaselineOutcome,
+    ReconstructionBase,
+    RedoAllSummary,
+    SAVEPOINT_FILE_NAME,
+    SCRATCH_SUBDIRECTORY_ENV_VAR,
+    SIDECAR_NAMING_ENV_VAR,
+    ScratchFileNaming,
+    SelectionRange,
+    SidecarDirectoryNaming,
+    SyncPolicy,
+    TARGET_CHECKSUM_MAX_FILE_BYTES,
+    TARGET_LOCK_STALE_AFTER,
+    TARGET_LOCK_WAIT,
+    TOGGLE_CACHE_MAX_AGE,
+    TargetFingerprint,
+    TargetReconstruction,
+    TextEncoding,
+    UNDO_TREE_BRANCH_PREFIX,
+    UndoAllSummary,
+    UndoBranch,
+    UndoConflict,
+    UndoCostEstimate,
+    UndoGroupOutcome,
+    UndoOptions,
+    UndoRedoToggleCache,
+    UndoValidationIssue,
+    UndoValidationReport,
+    WATCH_INTERVAL_MAX,
+    WATCH_INTERVAL_MIN,
+    WORKSPACE_JOURNAL_DIR_NAME,
+    WORKSPACE_ORDER_FILE_NAME,
+    WorkspaceUndo,
+    // Byte operations
+    add_single_byte_to_file,
+    add_single_byte_to_file_cancellable,
+    add_single_byte_to_file_with_progress,
+    overwrite_single_byte_in_place,
+    read_character_bytes_from_file,
+    read_single_byte_from_file,
+    remove_single_byte_from_file,
+    remove_single_byte_from_file_cancellable,
+    remove_single_byte_from_file_with_progress,
+    replace_file_with_draft,
+    replace_single_byte_in_file,
+    replace_single_byte_in_file_cancellable,
+    replace_single_byte_in_file_with_progress,
+    // Changelog creation
+    button_add_byte_make_log_file,
+    button_add_multibyte_make_log_files,
+    button_hexeditinplace_byte_make_log_file,
+    button_log_string_insert,
+    button_log_string_removal,
+    button_make_changelog_from_user_character_action_level,
+    button_make_hexedit_in_place_changelog,
+    button_remove_byte_make_log_file,
+    button_remove_multibyte_make_log_files,
+    read_text_encoding,
+    set_text_encoding,
+    // Redo stack management
+    button_base_clear_all_redo_logs,
+    button_safe_clear_all_redo_logs,
+    read_redo_depth_limit,
+    set_redo_depth_limit,
+    // Conflict resolution
+    button_check_next_undo_conflict,
+    // Undo / redo
+    button_undo_all,
+    button_undo_redo_next_at_generation,
+    button_undo_redo_next_inverse_changelog_pop_lifo,
+    button_undo_redo_next_with_conflict_resolution,
+    button_undo_redo_next_with_cursor,
+    button_undo_redo_next_with_options,
+    button_undo_redo_next_with_toggle_cache,
+    button_undo_redo_group,
+    button_undo_redo_group_with_options,
+    button_undo_redo_group_with_selection,
+    coalesce_repeated_hexedit,
+    coalesce_typed_insertion,
+    record_edit_cursor,
+    record_edit_selection,
+    redo_all,
+    redo_all_with_options,
+    validate_next_undo,
+    // Savepoints, bookmarks, undo-to-time
+    bookmark,
+    is_dirty_since_savepoint,
+    list_bookmarks,
+    mark_savepoint,
+    undo_to_bookmark,
+    undo_to_last_savepoint,
+    undo_to_timestamp,
+    // Undo tree
+    is_undo_tree_mode_enabled,
+    list_undo_branches,
+    set_undo_tree_mode,
+    switch_undo_branch,
+    // Combined apply-and-log
+    delete_to_line_end,
+    extend_file_with,
+    import_patch,
+    move_byte_range,
+    normalize_line_endings,
+    perform_and_log_add_byte,
+    perform_and_log_block_delete,
+    perform_and_log_block_insert,
+    perform_and_log_delete_line,
+    perform_and_log_hexedit_byte,
+    perform_and_log_insert_line,
+    perform_and_log_multi_cursor_edits,
+    perform_and_log_overwrite_character,
+    perform_and_log_remove_byte,
+    replace_file_contents,
+    truncate_file_to,
+    // In-place hex-edit mode
+    is_inplace_hexedit_mode_enabled,
+    set_inplace_hexedit_mode,
+    // Privacy mode
+    is_redaction_mode_enabled,
+    set_redaction_mode,
+    // Log codec
+    is_log_codec_set,
+    set_log_codec,
+    // Log signer
+    is_log_signer_set,
+    set_log_signer,
+    // Free space check
+    is_free_space_probe_set,
+    read_free_space_requirement,
+    set_free_space_probe,
+    set_free_space_requirement,
+    // Durability
+    is_backup_verification_enabled,
+    read_sync_policy,
+    recover_interrupted_operation,
+    scan_and_recover_orphans,
+    set_backup_verification,
+    set_sync_policy,
+    // Rebaseline / multi-process coordination
+    find_merge_conflicts,
+    merge_changelogs,
+    merge_changelogs_resolving,
+    migrate_changelog,
+    read_changelog_directory_identity,
+    read_changelog_generation,
+    rebaseline,
+    verify_changelog_generation,
+    // History maintenance
+    compact_changelog,
+    enforce_prune_policy,
+    read_prune_policy,
+    set_prune_policy,
+    enforce_ring_buffer_capacity,
+    read_ring_buffer_capacity,
+    set_ring_buffer_capacity,
+    changelog_directory_size,
+    read_changelog_quota,
+    set_changelog_quota,
+    read_changelog_storage,
+    set_changelog_storage,
+    read_log_encoding,
+    set_log_encoding,
+    audit_log_path,
+    read_audit_log_policy,
+    set_audit_log_policy,
+    gc_error_logs,
+    read_error_log_retention,
+    set_error_log_retention,
+    button_rename_target,
+    duplicate_changelog_for_save_as,
+    export_history_json,
+    import_history_json,
+    pack_changelog,
+    unpack_changelog,
+    // File operations
+    delete_file_with_undo,
+    record_file_created,
+    redo_file_operation,
+    rename_file_with_undo,
+    undo_file_operation,
+    // Checkpoints
+    create_checkpoint,
+    create_checkpoint_if_due,
+    list_checkpoints,
+    reconstruct_missing_target,
+    restore_to_checkpoint,
+    undo_steps_with_checkpoints,
+    undo_steps_with_checkpoints_with_options,
+    // History inspection
+    blame_range,
+    count_available_redos,
+    count_available_undos,
+    describe_next_undo,
+    diff_against_history,
+    estimate_undo_cost,
+    export_as_patch,
+    export_version_at_step,
+    history_by_author,
+    peek_next_log_set,
+    peek_next_redo,
+    peek_next_undo,
+    // Positioning
+    button_log_string_insert_at_char_index,
+    button_log_string_removal_at_char_index,
+    byte_offset_of_line_col,
+    byte_offset_past_bom,
+    char_index_to_byte_offset,
+    detect_byte_order_mark,
+    line_col_of_byte_offset,
+    perform_and_log_overwrite_character_at_char_index,
+    // Helpers
+    central_changelog_root,
+    changelog_author,
+    detect_utf8_byte_count,
+    get_log_file_letter_suffix,
+    get_redo_changelog_directory_path,
+    get_undo_changelog_directory_path,
+    log_button_error,
+    log_parse_mode,
+    normalize_long_path,
+    quarantine_bad_log,
+    quarantine_unparseable_logs,
+    scratch_file_naming,
+    set_central_changelog_root,
+    set_changelog_author,
+    set_log_parse_mode,
+    set_scratch_file_naming,
+    set_sidecar_directory_naming,
+    sidecar_directory_naming,
+};